@@ -1,19 +1,175 @@
 mod native_kernel;
 
-use anyhow::{bail, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use log::{debug, error, info};
 use std::env;
 use std::path::Path;
-use std::time::Instant;
+use signal_hook::consts::{SIGINT, SIGTERM};
+use signal_hook::flag;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
 use wasmtime::*;
 use native_kernel::NativeKernel;
 
 // --- CONFIGURATION ---
 const MOONBIT_KERNEL: &str = "../core/target/wasm/release/build/lib/lib.wasm";
 const MOCK_KERNEL: &str = "../core/mock_kernel/target/wasm32-unknown-unknown/release/mock_kernel.wasm";
+// Sub-step size for `process_tensor_stream_chunked`: large enough to stay
+// off the per-call/epoch-check overhead floor, small enough that the
+// watchdog and interrupt flag are re-checked well inside a human-noticeable
+// interval even mid-batch.
+const CHUNK_VECS: i32 = 4096;
+// Below this many available bytes, `process_tensor_stream_parallel` just
+// runs the serial path instead of paying thread-spawn overhead for a batch
+// too small to amortize it.
+const PARALLEL_MIN_BYTES: usize = 16384;
+
+// --- SNAPSHOT FORMAT ---
+// A flat, versioned blob: magic + version + the backend's declared get_cap()
+// (so restoring into a mismatched layout fails loudly) + the write head +
+// the read head (processing cursor), followed by one or more
+// length-prefixed byte regions.
+//
+// `read_head` only matters for `NativeBackend`: `WasmBackend`'s equivalent
+// cursor is a plain global inside the dumped linear memory, so it's already
+// captured and restored as part of that region and the header's read_head
+// is unused there (written as 0).
+const SNAPSHOT_MAGIC: u32 = 0x4D4F4F4E; // "MOON"
+const SNAPSHOT_VERSION: u32 = 2;
+
+struct SnapshotHeader {
+    write_head: i32,
+    read_head: i32,
+}
+
+fn write_snapshot_header(out: &mut Vec<u8>, cap: usize, write_head: i32, read_head: i32) {
+    out.extend_from_slice(&SNAPSHOT_MAGIC.to_le_bytes());
+    out.extend_from_slice(&SNAPSHOT_VERSION.to_le_bytes());
+    out.extend_from_slice(&(cap as u64).to_le_bytes());
+    out.extend_from_slice(&write_head.to_le_bytes());
+    out.extend_from_slice(&read_head.to_le_bytes());
+}
+
+fn write_len_prefixed(out: &mut Vec<u8>, data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u64).to_le_bytes());
+    out.extend_from_slice(data);
+}
+
+fn read_snapshot_header(data: &[u8], expected_cap: usize) -> Result<(SnapshotHeader, &[u8])> {
+    if data.len() < 24 {
+        bail!("Snapshot too short for header");
+    }
+    let magic = u32::from_le_bytes(data[0..4].try_into().unwrap());
+    if magic != SNAPSHOT_MAGIC {
+        bail!("Snapshot magic mismatch: not a Moonlight snapshot");
+    }
+    let version = u32::from_le_bytes(data[4..8].try_into().unwrap());
+    if version != SNAPSHOT_VERSION {
+        bail!("Snapshot version {} unsupported (expected {})", version, SNAPSHOT_VERSION);
+    }
+    let cap = u64::from_le_bytes(data[8..16].try_into().unwrap()) as usize;
+    if cap != expected_cap {
+        bail!("Snapshot capacity {} doesn't match this backend's capacity {} (mismatched layout)", cap, expected_cap);
+    }
+    let write_head = i32::from_le_bytes(data[16..20].try_into().unwrap());
+    let read_head = i32::from_le_bytes(data[20..24].try_into().unwrap());
+    Ok((SnapshotHeader { write_head, read_head }, &data[24..]))
+}
+
+fn read_len_prefixed(data: &[u8]) -> Result<(&[u8], &[u8])> {
+    if data.len() < 8 {
+        bail!("Snapshot truncated before a length prefix");
+    }
+    let len = u64::from_le_bytes(data[0..8].try_into().unwrap()) as usize;
+    let rest = &data[8..];
+    if rest.len() < len {
+        bail!("Snapshot truncated before its declared payload length");
+    }
+    Ok((&rest[..len], &rest[len..]))
+}
+
+// --- RING-BUFFER LOGGER ---
+// Wraps env_logger's own Logger and additionally retains the most recent
+// `capacity` formatted records in a ring buffer, so a `KERNEL PANIC` or
+// watchdog trip can dump recent debug/info context to stderr even when
+// the default filter level never printed it live.
+static LOG_RING: OnceLock<Arc<Mutex<VecDeque<String>>>> = OnceLock::new();
+
+struct RingLogger {
+    inner: env_logger::Logger,
+    ring: Arc<Mutex<VecDeque<String>>>,
+    capacity: usize,
+}
+
+impl log::Log for RingLogger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+        // Always true: the ring must capture debug/info records even when
+        // `inner`'s configured filter (used only to gate stderr output below)
+        // would otherwise have dropped them before they ever reached `log`.
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        let line = format!("[{}] {}: {}", record.level(), record.target(), record.args());
+        if let Ok(mut ring) = self.ring.lock() {
+            if ring.len() >= self.capacity {
+                ring.pop_front();
+            }
+            ring.push_back(line);
+        }
+        if self.inner.enabled(record.metadata()) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+/// Installs the ring-buffer logger as the global logger, sized to hold the
+/// last `capacity` records alongside the usual env_logger stderr output.
+fn init_logging(capacity: usize) {
+    let ring = Arc::new(Mutex::new(VecDeque::with_capacity(capacity)));
+    let _ = LOG_RING.set(ring.clone());
+
+    let inner = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).build();
+
+    log::set_boxed_logger(Box::new(RingLogger { inner, ring, capacity }))
+        .expect("logger already installed");
+    // Pin the global max level below the console filter so `debug!`/`trace!`
+    // call sites still reach `RingLogger::log` and land in the ring, even
+    // though `inner.enabled` will keep them off stderr.
+    log::set_max_level(log::LevelFilter::Trace);
+}
+
+/// Dumps the captured log tail to stderr for post-mortem context. A no-op
+/// if logging was never initialized through `init_logging`.
+fn dump_log_tail() {
+    if let Some(ring) = LOG_RING.get() {
+        if let Ok(ring) = ring.lock() {
+            eprintln!("--- captured log tail ({} records) ---", ring.len());
+            for line in ring.iter() {
+                eprintln!("{}", line);
+            }
+        }
+    }
+}
 
 // --- TRAIT DEFINITION ---
 
+/// Result of one bounded `process_tensor_stream_chunked` call: how much got
+/// done this step, how much was still sitting in the stream afterward, and
+/// whether the stream is now fully drained.
+struct ProcessProgress {
+    processed: i32,
+    remaining: i32,
+    done: bool,
+}
+
 trait KernelBackend {
     fn get_cap(&self) -> usize;
     fn get_input_offset(&self) -> usize;
@@ -21,6 +177,20 @@ trait KernelBackend {
 
     fn set_write_head(&mut self, pos: i32) -> Result<()>;
     fn process_tensor_stream(&mut self) -> Result<i32>;
+    /// Drives `process_tensor_stream` in a bounded sub-step of at most
+    /// `max_vecs` vectors, carrying the in-flight cursor as cheap state on
+    /// the kernel itself rather than restarting — so a huge backlog can be
+    /// processed cooperatively across several calls instead of one opaque
+    /// blocking one.
+    fn process_tensor_stream_chunked(&mut self, max_vecs: i32) -> Result<ProcessProgress>;
+    /// Parallel counterpart to `process_tensor_stream_chunked`: drains the
+    /// entire available backlog with a thread pool instead of one bounded
+    /// sub-step, trading fine-grained interruptibility for throughput on
+    /// large batches. Backends with no parallel implementation (e.g. wasm)
+    /// fall back to the serial whole-drain path.
+    fn process_tensor_stream_parallel(&mut self, _min_len_for_threads: usize) -> Result<i32> {
+        self.process_tensor_stream()
+    }
 
     fn vector_add_batch(&mut self, count: i32) -> Result<()>;
     fn vector_dot_batch(&mut self, count: i32) -> Result<()>;
@@ -28,6 +198,25 @@ trait KernelBackend {
 
     fn write_bytes(&mut self, offset: usize, data: &[u8]) -> Result<()>;
     fn read_bytes(&mut self, offset: usize, len: usize) -> Result<Vec<u8>>;
+    /// Like `read_bytes`, but copies directly into a caller-owned buffer
+    /// instead of allocating a fresh `Vec` — for hot paths called millions
+    /// of times per `--bench` run.
+    fn read_into(&mut self, offset: usize, buf: &mut [u8]) -> Result<()>;
+
+    /// `Some(engine)` for backends whose hot calls run inside a wasmtime
+    /// store, so a `Watchdog` can bump its epoch to interrupt a stalled
+    /// call. Native backends have no such mechanism, hence the default.
+    fn epoch_engine(&self) -> Option<Engine> {
+        None
+    }
+
+    /// Serializes the entire backend state (buffers + heads) to a
+    /// versioned blob so a run can be frozen and later resumed.
+    fn snapshot(&self) -> Result<Vec<u8>>;
+    /// Restores state previously produced by `snapshot`. Fails loudly if
+    /// the declared capacity or any region's length doesn't match this
+    /// backend's layout.
+    fn restore(&mut self, data: &[u8]) -> Result<()>;
 }
 
 // --- NATIVE BACKEND ---
@@ -67,6 +256,18 @@ impl KernelBackend for NativeBackend {
         Ok(self.kernel.process_tensor_stream())
     }
 
+    fn process_tensor_stream_chunked(&mut self, max_vecs: i32) -> Result<ProcessProgress> {
+        let max_bytes = (max_vecs.max(0) as usize) * 3;
+        let processed = self.kernel.process_tensor_stream_bounded(max_bytes);
+        let remaining = self.kernel.available();
+        Ok(ProcessProgress { processed, remaining, done: remaining == 0 })
+    }
+
+    #[cfg(feature = "parallel")]
+    fn process_tensor_stream_parallel(&mut self, min_len_for_threads: usize) -> Result<i32> {
+        Ok(self.kernel.process_tensor_stream_parallel(min_len_for_threads))
+    }
+
     fn vector_add_batch(&mut self, count: i32) -> Result<()> {
         self.kernel.vector_add_batch(count);
         Ok(())
@@ -114,12 +315,62 @@ impl KernelBackend for NativeBackend {
              Ok(self.kernel.output_buffer[rel_offset..rel_offset+len].to_vec())
          }
     }
+
+    fn read_into(&mut self, offset: usize, buf: &mut [u8]) -> Result<()> {
+        let len = buf.len();
+        if offset < native_kernel::BUFFER_SIZE {
+            buf.copy_from_slice(&self.kernel.buffer[offset..offset+len]);
+        } else {
+            let rel_offset = offset - native_kernel::BUFFER_SIZE;
+            buf.copy_from_slice(&self.kernel.output_buffer[rel_offset..rel_offset+len]);
+        }
+        Ok(())
+    }
+
+    fn snapshot(&self) -> Result<Vec<u8>> {
+        let mut out = Vec::with_capacity(24 + 16 + self.kernel.buffer.len() + self.kernel.output_buffer.len());
+        write_snapshot_header(&mut out, self.get_cap(), self.kernel.write_head as i32, self.kernel.get_read_head());
+        write_len_prefixed(&mut out, &self.kernel.buffer);
+        write_len_prefixed(&mut out, &self.kernel.output_buffer);
+        Ok(out)
+    }
+
+    fn restore(&mut self, data: &[u8]) -> Result<()> {
+        let (header, rest) = read_snapshot_header(data, self.get_cap())?;
+        let (buf, rest) = read_len_prefixed(rest)?;
+        let (out_buf, _) = read_len_prefixed(rest)?;
+
+        if buf.len() != self.kernel.buffer.len() || out_buf.len() != self.kernel.output_buffer.len() {
+            bail!("Snapshot buffer length doesn't match the native kernel's layout");
+        }
+
+        self.kernel.buffer.copy_from_slice(buf);
+        self.kernel.output_buffer.copy_from_slice(out_buf);
+        self.kernel.set_write_head(header.write_head);
+        self.kernel.set_read_head(header.read_head);
+        Ok(())
+    }
+}
+
+// --- HOST IMPORTS ---
+// State shared with the host functions a Wasm kernel can import from the
+// "moonlight_host" module. Lives on the Store so closures registered via
+// `Linker::func_wrap` can reach it through `Caller::data()`.
+struct HostCtx {
+    start: Instant,
+}
+
+impl HostCtx {
+    fn new() -> Self {
+        Self { start: Instant::now() }
+    }
 }
 
 // --- WASM BACKEND ---
 
 struct WasmBackend {
-    store: Store<()>,
+    engine: Engine,
+    store: Store<HostCtx>,
     memory: Memory,
     cap: usize,
     input_offset: usize,
@@ -130,19 +381,56 @@ struct WasmBackend {
     vector_add_batch: Option<TypedFunc<i32, i32>>,
     vector_dot_batch: Option<TypedFunc<i32, i32>>,
     check_integrity: Option<TypedFunc<(), i32>>,
+
+    // The kernel exports no `get_write_head`, so a snapshot needs this
+    // cached on the host side to re-apply it on restore.
+    last_write_head: i32,
 }
 
 impl WasmBackend {
     fn new(kernel_path: &str, strict_mode: bool) -> Result<Self> {
         let mut config = Config::new();
         config.wasm_multi_memory(true);
+        // Lets a Watchdog interrupt a stalled call by bumping the epoch
+        // from its own thread instead of the kernel ever seeing it.
+        config.epoch_interruption(true);
         let engine = Engine::new(&config)?;
-        let mut store = Store::new(&engine, ());
+        let mut store = Store::new(&engine, HostCtx::new());
+        store.set_epoch_deadline(1);
 
         let module = Module::from_file(&engine, kernel_path)
             .with_context(|| format!("Failed to load Wasm at '{}'", kernel_path))?;
 
-        let linker = Linker::new(&engine);
+        // Host-function imports under "moonlight_host": a kernel can import
+        // any subset (or none) of the functions registered here and still
+        // instantiate, since a Linker only wires up what a module actually
+        // declares as an import. `host_log` is always available; the richer
+        // telemetry surface is only registered in --strict, so outside it a
+        // kernel importing `host_metric` or `host_time_ns` fails to
+        // instantiate.
+        let mut linker = Linker::new(&engine);
+        linker.func_wrap("moonlight_host", "host_log", |mut caller: Caller<'_, HostCtx>, ptr: i32, len: i32| {
+            let memory = match caller.get_export("memory") {
+                Some(Extern::Memory(m)) => m,
+                _ => return,
+            };
+            let mut buf = vec![0u8; len as usize];
+            if memory.read(&mut caller, ptr as usize, &mut buf).is_ok() {
+                if let Ok(s) = std::str::from_utf8(&buf) {
+                    info!("[kernel] {}", s);
+                }
+            }
+        })?;
+
+        if strict_mode {
+            linker.func_wrap("moonlight_host", "host_metric", |_caller: Caller<'_, HostCtx>, id: i32, value: f64| {
+                debug!("[kernel metric] id={} value={}", id, value);
+            })?;
+            linker.func_wrap("moonlight_host", "host_time_ns", |caller: Caller<'_, HostCtx>| -> i64 {
+                caller.data().start.elapsed().as_nanos() as i64
+            })?;
+        }
+
         let instance = linker.instantiate(&mut store, &module)
             .context("Failed to instantiate Wasm module")?;
 
@@ -188,6 +476,7 @@ impl WasmBackend {
         }
 
         Ok(Self {
+            engine,
             store,
             memory,
             cap,
@@ -198,6 +487,7 @@ impl WasmBackend {
             vector_add_batch,
             vector_dot_batch,
             check_integrity,
+            last_write_head: 0,
         })
     }
 }
@@ -207,8 +497,13 @@ impl KernelBackend for WasmBackend {
     fn get_input_offset(&self) -> usize { self.input_offset }
     fn get_output_offset(&self) -> usize { self.output_offset }
 
+    fn epoch_engine(&self) -> Option<Engine> {
+        Some(self.engine.clone())
+    }
+
     fn set_write_head(&mut self, pos: i32) -> Result<()> {
         self.set_write_head.call(&mut self.store, pos)?;
+        self.last_write_head = pos;
         Ok(())
     }
 
@@ -216,6 +511,15 @@ impl KernelBackend for WasmBackend {
         Ok(self.process_tensor_stream.call(&mut self.store, ())?)
     }
 
+    fn process_tensor_stream_chunked(&mut self, _max_vecs: i32) -> Result<ProcessProgress> {
+        // The Wasm kernel exports no bounded variant: each call drains
+        // everything available in one opaque step, so a "chunk" here is
+        // just the whole stream — `max_vecs` is accepted for interface
+        // parity with the native backend but has no effect.
+        let processed = self.process_tensor_stream.call(&mut self.store, ())?;
+        Ok(ProcessProgress { processed, remaining: 0, done: true })
+    }
+
     fn vector_add_batch(&mut self, count: i32) -> Result<()> {
         if let Some(f) = &self.vector_add_batch {
             f.call(&mut self.store, count)?;
@@ -248,6 +552,86 @@ impl KernelBackend for WasmBackend {
         self.memory.read(&mut self.store, offset, &mut buf)?;
         Ok(buf)
     }
+
+    fn read_into(&mut self, offset: usize, buf: &mut [u8]) -> Result<()> {
+        self.memory.read(&mut self.store, offset, buf)?;
+        Ok(())
+    }
+
+    fn snapshot(&self) -> Result<Vec<u8>> {
+        let mem = self.memory.data(&self.store);
+        let mut out = Vec::with_capacity(24 + 8 + mem.len());
+        // read_head is already part of the dumped linear memory below, so
+        // the header's copy is unused on restore; pass 0 rather than a
+        // value that would wrongly imply it's authoritative here.
+        write_snapshot_header(&mut out, self.cap, self.last_write_head, 0);
+        write_len_prefixed(&mut out, mem);
+        Ok(out)
+    }
+
+    fn restore(&mut self, data: &[u8]) -> Result<()> {
+        let (header, rest) = read_snapshot_header(data, self.cap)?;
+        let (mem_bytes, _) = read_len_prefixed(rest)?;
+
+        if mem_bytes.len() != self.memory.data_size(&self.store) {
+            bail!("Snapshot linear memory size doesn't match this module's memory");
+        }
+
+        self.memory.write(&mut self.store, 0, mem_bytes)?;
+        self.set_write_head(header.write_head)?;
+        Ok(())
+    }
+}
+
+// --- WATCHDOG ---
+// Detects a stalled process_tensor_stream/vector_add_batch/vector_dot_batch
+// call (e.g. a buggy Wasm kernel spinning forever). A monitor thread must
+// be "petted" each kinetic-loop iteration via an atomic timestamp; if no pet
+// arrives within `timeout_ms`, it trips and, for a Wasm backend, bumps the
+// engine's epoch so the in-flight call traps and returns an error instead
+// of wedging the process (mirrors a virtio-watchdog).
+struct Watchdog {
+    start: Instant,
+    last_pet_ms: Arc<AtomicU64>,
+    tripped: Arc<AtomicBool>,
+}
+
+impl Watchdog {
+    fn start(timeout_ms: u64, engine: Option<Engine>) -> Self {
+        let start = Instant::now();
+        let last_pet_ms = Arc::new(AtomicU64::new(0));
+        let tripped = Arc::new(AtomicBool::new(false));
+
+        let pet_handle = last_pet_ms.clone();
+        let tripped_handle = tripped.clone();
+        let poll_interval = Duration::from_millis(std::cmp::max(timeout_ms / 4, 1));
+
+        thread::spawn(move || loop {
+            thread::sleep(poll_interval);
+            let now_ms = start.elapsed().as_millis() as u64;
+            let since_pet = now_ms.saturating_sub(pet_handle.load(Ordering::Relaxed));
+
+            if since_pet > timeout_ms {
+                tripped_handle.store(true, Ordering::SeqCst);
+                // Bump the epoch on every poll past the deadline: the
+                // kernel might not have made its first epoch-checked call
+                // yet when we first trip, so one bump could be missed.
+                if let Some(engine) = &engine {
+                    engine.increment_epoch();
+                }
+            }
+        });
+
+        Self { start, last_pet_ms, tripped }
+    }
+
+    fn pet(&self) {
+        self.last_pet_ms.store(self.start.elapsed().as_millis() as u64, Ordering::Relaxed);
+    }
+
+    fn tripped(&self) -> bool {
+        self.tripped.load(Ordering::SeqCst)
+    }
 }
 
 // --- CONTROLLER ---
@@ -255,10 +639,26 @@ impl KernelBackend for WasmBackend {
 struct MoonlightBridge {
     backend: Box<dyn KernelBackend>,
     noise_buffer: Vec<u8>,
+    // Reused by the verify path in `run_kinetic_loop` instead of letting
+    // `read_bytes` allocate a fresh `Vec` on every iteration.
+    verify_scratch: Vec<u8>,
+    watchdog: Option<Watchdog>,
+    // Set by a SIGINT/SIGTERM handler so a long `--bench` run can be
+    // interrupted cleanly: checked at the top of each kinetic-loop
+    // iteration instead of letting the signal kill the process outright.
+    interrupted: Arc<AtomicBool>,
+    // When set, a KERNEL PANIC or watchdog trip dumps the captured log
+    // tail (see `dump_log_tail`) to stderr before returning the error.
+    dump_log_on_error: bool,
+    // When set, `run_kinetic_loop` drives `process_tensor_stream_parallel`
+    // instead of the bounded chunked path. Only `NativeBackend` (behind the
+    // `parallel` feature) has a real parallel implementation; other
+    // backends silently fall back to their serial whole-drain behavior.
+    parallel_mode: bool,
 }
 
 impl MoonlightBridge {
-    fn ignite(kernel_path: Option<&str>, strict_mode: bool) -> Result<Self> {
+    fn ignite(kernel_path: Option<&str>, strict_mode: bool, watchdog_ms: Option<u64>, dump_log_on_error: bool, parallel_mode: bool) -> Result<Self> {
         // Mode Selection
         let backend: Box<dyn KernelBackend> = match kernel_path {
             Some(path) if Path::new(path).exists() => {
@@ -271,33 +671,74 @@ impl MoonlightBridge {
             }
         };
 
-        // Optimization: Pre-allocate noise buffer
-        // Default batch is 1024, so 3072 bytes. We allocate 4KB to be safe.
-        let mut noise_buffer = vec![0u8; 4096];
+        // Optimization: pre-size every scratch buffer once, from the
+        // backend's own declared capacity, instead of growing (and
+        // re-filling) them piecemeal on the hot path later. Mirrors the
+        // wasmi technique of extending a stack once up front rather than
+        // repeatedly pushing.
+        let cap = backend.get_cap();
+
+        let mut noise_buffer = vec![0u8; cap];
         for (i, byte) in noise_buffer.iter_mut().enumerate() {
             *byte = ((i % 255) ^ 0xAA) as u8;
         }
 
-        let bridge = Self { backend, noise_buffer };
+        let verify_scratch = vec![0u8; cap];
+
+        let watchdog = watchdog_ms.map(|ms| {
+            info!("Watchdog armed: {}ms timeout", ms);
+            Watchdog::start(ms, backend.epoch_engine())
+        });
+
+        let interrupted = Arc::new(AtomicBool::new(false));
+        flag::register(SIGINT, Arc::clone(&interrupted)).context("Failed to register SIGINT handler")?;
+        flag::register(SIGTERM, Arc::clone(&interrupted)).context("Failed to register SIGTERM handler")?;
+
+        let bridge = Self { backend, noise_buffer, verify_scratch, watchdog, interrupted, dump_log_on_error, parallel_mode };
 
         // Validation of Layout
-        let cap = bridge.backend.get_cap();
         debug!("Backend Capacity: {} bytes", cap);
 
         Ok(bridge)
     }
 
+    /// Turns a backend-call error into the watchdog's panic message when
+    /// the call failed because the watchdog tripped (and bumped the epoch
+    /// out from under it); otherwise passes the original error through.
+    fn watchdog_checked(&mut self, err: anyhow::Error) -> anyhow::Error {
+        if self.watchdog.as_ref().map_or(false, Watchdog::tripped) {
+            let _ = self.backend.check_integrity();
+            error!("KERNEL PANIC: watchdog timeout");
+            if self.dump_log_on_error {
+                dump_log_tail();
+            }
+            anyhow!("KERNEL PANIC: watchdog timeout")
+        } else {
+            err
+        }
+    }
+
+    fn snapshot(&self) -> Result<Vec<u8>> {
+        self.backend.snapshot()
+    }
+
+    fn restore(&mut self, data: &[u8]) -> Result<()> {
+        self.backend.restore(data)
+    }
+
     fn write_batch(&mut self, write_pos: usize, count: usize) -> Result<usize> {
         let cap = self.backend.get_cap();
         let input_offset = self.backend.get_input_offset();
 
         let bytes_needed = count * 3;
 
-        // Lazy resize if batch size increases
+        // Lazy resize if batch size increases. Only the newly grown tail
+        // needs its pattern filled in — everything before `old_len` was
+        // already generated by a previous resize (or by `ignite`).
         if self.noise_buffer.len() < bytes_needed {
+             let old_len = self.noise_buffer.len();
              self.noise_buffer.resize(bytes_needed, 0);
-             // Regenerate pattern for new size (simplified for speed, just fill tail)
-             for i in 0..bytes_needed {
+             for i in old_len..bytes_needed {
                  self.noise_buffer[i] = ((i % 255) ^ 0xAA) as u8;
              }
         }
@@ -322,8 +763,11 @@ impl MoonlightBridge {
         Ok(end_pos % cap)
     }
 
-    fn run_kinetic_loop(&mut self, iterations: usize, batch_size: usize, verify_active: bool) -> Result<()> {
+    fn run_kinetic_loop(&mut self, iterations: usize, batch_size: usize, verify_active: bool, bench_mode: bool) -> Result<()> {
         if self.backend.check_integrity()? == 0 {
+             if self.dump_log_on_error {
+                 dump_log_tail();
+             }
              bail!("KERNEL PANIC: Integrity Check Failed on Startup!");
         }
 
@@ -334,12 +778,50 @@ impl MoonlightBridge {
 
         let start = Instant::now();
 
+        let mut completed = 0usize;
         for i in 0..iterations {
+            if self.interrupted.load(Ordering::Relaxed) {
+                info!("Interrupt received, stopping after {} of {} iterations", i, iterations);
+                break;
+            }
+
+            if let Some(wd) = &self.watchdog { wd.pet(); }
+
             write_pos = self.write_batch(write_pos, batch_size)?;
 
-            self.backend.set_write_head(write_pos as i32)?;
+            self.backend.set_write_head(write_pos as i32).map_err(|e| self.watchdog_checked(e))?;
+
+            // Drive processing in bounded chunks rather than one opaque
+            // call, re-checking the watchdog and interrupt flag between
+            // sub-steps so a single oversized batch can still be
+            // interrupted fairly mid-stream.
+            //
+            // `--parallel` trades that fairness for throughput: it drains
+            // the whole backlog in one `process_tensor_stream_parallel`
+            // call per iteration instead of looping over bounded sub-steps.
+            let mut processed_bytes = 0i32;
+            if self.parallel_mode {
+                if let Some(wd) = &self.watchdog { wd.pet(); }
+                processed_bytes = self.backend
+                    .process_tensor_stream_parallel(PARALLEL_MIN_BYTES)
+                    .map_err(|e| self.watchdog_checked(e))?;
+            } else {
+                loop {
+                    if let Some(wd) = &self.watchdog { wd.pet(); }
+                    if self.interrupted.load(Ordering::Relaxed) {
+                        break;
+                    }
+
+                    let progress = self.backend
+                        .process_tensor_stream_chunked(CHUNK_VECS)
+                        .map_err(|e| self.watchdog_checked(e))?;
+                    processed_bytes += progress.processed;
 
-            let processed_bytes = self.backend.process_tensor_stream()?;
+                    if progress.done || progress.processed == 0 {
+                        break;
+                    }
+                }
+            }
             let processed_vecs = processed_bytes / 3;
 
             if verify_active && i == 0 {
@@ -348,7 +830,8 @@ impl MoonlightBridge {
                 for k in 0..limit {
                     let idx = (read_pos + k * 3) % cap;
                     let offset = output_offset + idx;
-                    let vec_data = self.backend.read_bytes(offset, 3)?;
+                    let vec_data = &mut self.verify_scratch[0..3];
+                    self.backend.read_into(offset, vec_data)?;
 
                     let ox = vec_data[0];
                     let oy = vec_data[1];
@@ -364,18 +847,22 @@ impl MoonlightBridge {
             }
 
             if i % 10 == 0 && processed_vecs > 0 {
-                self.backend.vector_add_batch(processed_vecs)?;
+                self.backend.vector_add_batch(processed_vecs).map_err(|e| self.watchdog_checked(e))?;
             }
             if i % 20 == 0 && processed_vecs > 0 {
-                self.backend.vector_dot_batch(processed_vecs)?;
+                self.backend.vector_dot_batch(processed_vecs).map_err(|e| self.watchdog_checked(e))?;
             }
 
             read_pos = (read_pos + processed_bytes as usize) % cap;
+            completed = i + 1;
         }
 
         let duration = start.elapsed();
-        if iterations > 100 {
-            let total_vecs = iterations as u128 * batch_size as u128;
+        // Benchmark throughput lines belong to `--bench` runs; for an
+        // ordinary run, print them only if an interrupt cut it short
+        // (so a partial run still surfaces the numbers computed so far).
+        if (bench_mode || self.interrupted.load(Ordering::Relaxed)) && completed > 0 {
+            let total_vecs = completed as u128 * batch_size as u128;
             let vecs_per_sec = total_vecs as f64 / duration.as_secs_f64();
             let bytes_per_sec = (total_vecs * 3) as f64 / duration.as_secs_f64();
             let mb_per_sec = bytes_per_sec / 1_048_576.0;
@@ -387,6 +874,9 @@ impl MoonlightBridge {
         }
 
         if self.backend.check_integrity()? == 0 {
+             if self.dump_log_on_error {
+                 dump_log_tail();
+             }
              bail!("KERNEL PANIC: Integrity Check Failed after Kinetic Loop!");
         }
 
@@ -395,21 +885,39 @@ impl MoonlightBridge {
 }
 
 fn main() -> Result<()> {
-    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
-
     let args: Vec<String> = env::args().collect();
     let bench_mode = args.iter().any(|a| a == "--bench");
     let strict_mode = args.iter().any(|a| a == "--strict");
+    let dump_log_on_error = args.iter().any(|a| a == "--dump-log-on-error");
+    let parallel_mode = args.iter().any(|a| a == "--parallel");
 
     let mut kernel_path = None;
+    let mut watchdog_ms: Option<u64> = None;
+    let mut snapshot_path: Option<&str> = None;
+    let mut restore_path: Option<&str> = None;
+    let mut log_capture: usize = 256;
     let mut i = 1;
     while i < args.len() {
         if args[i] == "--kernel" && i + 1 < args.len() {
             kernel_path = Some(args[i + 1].as_str());
         }
+        if args[i] == "--watchdog-ms" && i + 1 < args.len() {
+            watchdog_ms = Some(args[i + 1].parse().context("--watchdog-ms expects an integer")?);
+        }
+        if args[i] == "--snapshot" && i + 1 < args.len() {
+            snapshot_path = Some(args[i + 1].as_str());
+        }
+        if args[i] == "--restore" && i + 1 < args.len() {
+            restore_path = Some(args[i + 1].as_str());
+        }
+        if args[i] == "--log-capture" && i + 1 < args.len() {
+            log_capture = args[i + 1].parse().context("--log-capture expects an integer")?;
+        }
         i += 1;
     }
 
+    init_logging(log_capture);
+
     // Explicit path > Default paths > None (Native)
     let final_path = if let Some(p) = kernel_path {
         Some(p)
@@ -421,13 +929,25 @@ fn main() -> Result<()> {
         None
     };
 
-    let mut bridge = MoonlightBridge::ignite(final_path, strict_mode)?;
+    let mut bridge = MoonlightBridge::ignite(final_path, strict_mode, watchdog_ms, dump_log_on_error, parallel_mode)?;
+
+    if let Some(path) = restore_path {
+        let data = std::fs::read(path).with_context(|| format!("Failed to read restore snapshot '{}'", path))?;
+        bridge.restore(&data).with_context(|| format!("Failed to restore snapshot '{}'", path))?;
+        info!("Restored state from '{}'", path);
+    }
 
     let iterations = if bench_mode { 100_000 } else { 5 };
     // Native Cap is 65536, same as default
     let batch_size = 1024;
 
-    bridge.run_kinetic_loop(iterations, batch_size, !bench_mode)?;
+    bridge.run_kinetic_loop(iterations, batch_size, !bench_mode, bench_mode)?;
+
+    if let Some(path) = snapshot_path {
+        let data = bridge.snapshot()?;
+        std::fs::write(path, &data).with_context(|| format!("Failed to write snapshot '{}'", path))?;
+        info!("Wrote snapshot to '{}' ({} bytes)", path, data.len());
+    }
 
     Ok(())
 }
@@ -2,6 +2,38 @@
 pub const BUFFER_SIZE: usize = 65536;
 pub const MASK: usize = BUFFER_SIZE - 1;
 
+/// Which explicit SIMD backend the native kernel picked at construction time.
+///
+/// Detection is a one-time cost (`NativeKernel::new`); `process_tensor_stream`
+/// just matches on the stored value instead of re-probing CPUID/HWCAP on
+/// every call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KernelSimdLevel {
+    Scalar,
+    #[cfg(target_arch = "x86_64")]
+    Avx2,
+    #[cfg(target_arch = "aarch64")]
+    Neon,
+}
+
+impl KernelSimdLevel {
+    fn detect() -> Self {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") {
+                return KernelSimdLevel::Avx2;
+            }
+        }
+        #[cfg(target_arch = "aarch64")]
+        {
+            if std::arch::is_aarch64_feature_detected!("neon") {
+                return KernelSimdLevel::Neon;
+            }
+        }
+        KernelSimdLevel::Scalar
+    }
+}
+
 #[allow(dead_code)]
 pub struct NativeKernel {
     pub buffer: Vec<u8>,
@@ -10,6 +42,7 @@ pub struct NativeKernel {
     pub tail_canary: u8,
     pub read_head: usize,
     pub write_head: usize,
+    pub simd_level: KernelSimdLevel,
 }
 
 impl NativeKernel {
@@ -21,9 +54,15 @@ impl NativeKernel {
             tail_canary: 0x55,
             read_head: 0,
             write_head: 0,
+            simd_level: KernelSimdLevel::detect(),
         }
     }
 
+    #[allow(dead_code)]
+    pub fn simd_level(&self) -> KernelSimdLevel {
+        self.simd_level
+    }
+
     #[allow(dead_code)]
     pub fn get_buffer_size(&self) -> i32 {
         BUFFER_SIZE as i32
@@ -40,6 +79,17 @@ impl NativeKernel {
         self.read_head as i32
     }
 
+    pub fn set_read_head(&mut self, pos: i32) {
+        if pos >= 0 {
+            self.read_head = (pos as usize) & MASK;
+        }
+    }
+
+    /// Bytes still sitting between `read_head` and `write_head`, unprocessed.
+    pub fn available(&self) -> i32 {
+        self.diff() as i32
+    }
+
     #[allow(dead_code)]
     pub fn get_output_byte(&self, index: i32) -> i32 {
         if index >= 0 {
@@ -88,8 +138,22 @@ impl NativeKernel {
         self.output_buffer[(idx + 2) & MASK] = (nz * 100.0 + 100.0) as u8;
     }
 
+    /// Normalize one (x, y, z) lane the same way every backend (scalar,
+    /// AVX2, NEON) must: `len_sq == 0.0` preserves the original triple
+    /// instead of dividing by zero.
     #[inline(always)]
-    fn process_contiguous_chunk_simd(in_slice: &[u8], out_slice: &mut [u8]) {
+    fn normalize_lane_scalar(x: f32, y: f32, z: f32) -> (f32, f32, f32) {
+        let len_sq = x * x + y * y + z * z;
+        if len_sq > 0.0 {
+            let len = len_sq.sqrt();
+            (x / len, y / len, z / len)
+        } else {
+            (x, y, z)
+        }
+    }
+
+    #[inline(always)]
+    fn process_contiguous_chunk_scalar(in_slice: &[u8], out_slice: &mut [u8]) {
         // Optimized for larger chunks: 48 bytes = 16 vectors
         // This helps the compiler auto-vectorize more aggressively
         let mut chunks_in = in_slice.chunks_exact(48);
@@ -102,14 +166,7 @@ impl NativeKernel {
                 let y = inc[off+1] as f32;
                 let z = inc[off+2] as f32;
 
-                let len_sq = x*x + y*y + z*z;
-                // Branchless select (approximate for f32)
-                let (nx, ny, nz) = if len_sq > 0.0 {
-                    let len = len_sq.sqrt();
-                    (x/len, y/len, z/len)
-                } else {
-                    (x, y, z)
-                };
+                let (nx, ny, nz) = Self::normalize_lane_scalar(x, y, z);
 
                 outc[off] = (nx * 100.0 + 100.0) as u8;
                 outc[off+1] = (ny * 100.0 + 100.0) as u8;
@@ -131,13 +188,7 @@ impl NativeKernel {
                 let y = inc[off+1] as f32;
                 let z = inc[off+2] as f32;
 
-                let len_sq = x*x + y*y + z*z;
-                let (nx, ny, nz) = if len_sq > 0.0 {
-                    let len = len_sq.sqrt();
-                    (x/len, y/len, z/len)
-                } else {
-                    (x, y, z)
-                };
+                let (nx, ny, nz) = Self::normalize_lane_scalar(x, y, z);
 
                 outc[off] = (nx * 100.0 + 100.0) as u8;
                 outc[off+1] = (ny * 100.0 + 100.0) as u8;
@@ -146,7 +197,211 @@ impl NativeKernel {
         }
     }
 
+    /// AVX2 path: 8 vectors (`f32x8` lanes) per iteration. Vectors are
+    /// packed as 3-byte triples, so lanes are gathered (deinterleaved) into
+    /// separate x/y/z registers rather than loaded contiguously.
+    ///
+    /// Uses `_mm256_rsqrt_ps` plus one Newton-Raphson step rather than an
+    /// exact reciprocal sqrt, so at rounding boundaries the output `u8` can
+    /// differ by one from the scalar path for the same input (the NEON path
+    /// uses the same rsqrt-plus-one-step shape and can differ too). Harmless
+    /// for the normalize itself, but it means `process_tensor_stream`'s
+    /// output is not guaranteed to be bit-for-bit identical across hosts
+    /// with different detected `KernelSimdLevel`s.
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx2")]
+    unsafe fn process_contiguous_chunk_avx2(in_slice: &[u8], out_slice: &mut [u8]) {
+        use std::arch::x86_64::*;
+
+        let mut chunks_in = in_slice.chunks_exact(48);
+        let mut chunks_out = out_slice.chunks_exact_mut(48);
+
+        for (inc, outc) in chunks_in.by_ref().zip(chunks_out.by_ref()) {
+            // Two 8-lane batches cover the 16 vectors in a 48-byte block.
+            for batch in 0..2 {
+                let base = batch * 8;
+                let mut xs = [0f32; 8];
+                let mut ys = [0f32; 8];
+                let mut zs = [0f32; 8];
+                for lane in 0..8 {
+                    let off = (base + lane) * 3;
+                    xs[lane] = inc[off] as f32;
+                    ys[lane] = inc[off + 1] as f32;
+                    zs[lane] = inc[off + 2] as f32;
+                }
+
+                let x = _mm256_loadu_ps(xs.as_ptr());
+                let y = _mm256_loadu_ps(ys.as_ptr());
+                let z = _mm256_loadu_ps(zs.as_ptr());
+
+                let len_sq = _mm256_add_ps(_mm256_add_ps(_mm256_mul_ps(x, x), _mm256_mul_ps(y, y)), _mm256_mul_ps(z, z));
+
+                // Reciprocal-sqrt approximation plus one Newton-Raphson
+                // refinement step for accuracy: r = r*(1.5 - 0.5*len_sq*r*r)
+                let r0 = _mm256_rsqrt_ps(len_sq);
+                let half = _mm256_set1_ps(0.5);
+                let three_halves = _mm256_set1_ps(1.5);
+                let r_sq = _mm256_mul_ps(r0, r0);
+                let nr_term = _mm256_sub_ps(three_halves, _mm256_mul_ps(half, _mm256_mul_ps(len_sq, r_sq)));
+                let r = _mm256_mul_ps(r0, nr_term);
+
+                let nx = _mm256_mul_ps(x, r);
+                let ny = _mm256_mul_ps(y, r);
+                let nz = _mm256_mul_ps(z, r);
+
+                // Per-lane mask: len_sq == 0.0 keeps the original (x, y, z)
+                // instead of the rsqrt result.
+                let zero_mask = _mm256_cmp_ps(len_sq, _mm256_setzero_ps(), _CMP_EQ_OQ);
+                let sel_x = _mm256_blendv_ps(nx, x, zero_mask);
+                let sel_y = _mm256_blendv_ps(ny, y, zero_mask);
+                let sel_z = _mm256_blendv_ps(nz, z, zero_mask);
+
+                let scale = _mm256_set1_ps(100.0);
+                let bias = _mm256_set1_ps(100.0);
+                let ox = _mm256_add_ps(_mm256_mul_ps(sel_x, scale), bias);
+                let oy = _mm256_add_ps(_mm256_mul_ps(sel_y, scale), bias);
+                let oz = _mm256_add_ps(_mm256_mul_ps(sel_z, scale), bias);
+
+                let mut out_x = [0f32; 8];
+                let mut out_y = [0f32; 8];
+                let mut out_z = [0f32; 8];
+                _mm256_storeu_ps(out_x.as_mut_ptr(), ox);
+                _mm256_storeu_ps(out_y.as_mut_ptr(), oy);
+                _mm256_storeu_ps(out_z.as_mut_ptr(), oz);
+
+                for lane in 0..8 {
+                    let off = (base + lane) * 3;
+                    outc[off] = out_x[lane].clamp(0.0, 255.0) as u8;
+                    outc[off + 1] = out_y[lane].clamp(0.0, 255.0) as u8;
+                    outc[off + 2] = out_z[lane].clamp(0.0, 255.0) as u8;
+                }
+            }
+        }
+
+        // Remainder (<48 bytes) falls back to the scalar lane math.
+        let remainder_in = chunks_in.remainder();
+        let remainder_out = chunks_out.into_remainder();
+
+        let mut sub_chunks_in = remainder_in.chunks_exact(12);
+        let mut sub_chunks_out = remainder_out.chunks_exact_mut(12);
+        for (inc, outc) in sub_chunks_in.by_ref().zip(sub_chunks_out.by_ref()) {
+            for i in 0..4 {
+                let off = i * 3;
+                let (nx, ny, nz) = Self::normalize_lane_scalar(inc[off] as f32, inc[off + 1] as f32, inc[off + 2] as f32);
+                outc[off] = (nx * 100.0 + 100.0) as u8;
+                outc[off + 1] = (ny * 100.0 + 100.0) as u8;
+                outc[off + 2] = (nz * 100.0 + 100.0) as u8;
+            }
+        }
+    }
+
+    /// NEON path: 4 vectors (`f32x4` lanes) per iteration, mirroring the
+    /// AVX2 gather/compute/scatter shape at half the lane width.
+    #[cfg(target_arch = "aarch64")]
+    #[target_feature(enable = "neon")]
+    unsafe fn process_contiguous_chunk_neon(in_slice: &[u8], out_slice: &mut [u8]) {
+        use std::arch::aarch64::*;
+
+        let mut chunks_in = in_slice.chunks_exact(48);
+        let mut chunks_out = out_slice.chunks_exact_mut(48);
+
+        for (inc, outc) in chunks_in.by_ref().zip(chunks_out.by_ref()) {
+            for batch in 0..4 {
+                let base = batch * 4;
+                let mut xs = [0f32; 4];
+                let mut ys = [0f32; 4];
+                let mut zs = [0f32; 4];
+                for lane in 0..4 {
+                    let off = (base + lane) * 3;
+                    xs[lane] = inc[off] as f32;
+                    ys[lane] = inc[off + 1] as f32;
+                    zs[lane] = inc[off + 2] as f32;
+                }
+
+                let x = vld1q_f32(xs.as_ptr());
+                let y = vld1q_f32(ys.as_ptr());
+                let z = vld1q_f32(zs.as_ptr());
+
+                let len_sq = vaddq_f32(vaddq_f32(vmulq_f32(x, x), vmulq_f32(y, y)), vmulq_f32(z, z));
+
+                // vrsqrteq_f32 + one Newton-Raphson step, same shape as AVX2.
+                let r0 = vrsqrteq_f32(len_sq);
+                let r = vmulq_f32(vrsqrtsq_f32(len_sq, vmulq_f32(r0, r0)), r0);
+
+                let nx = vmulq_f32(x, r);
+                let ny = vmulq_f32(y, r);
+                let nz = vmulq_f32(z, r);
+
+                let zero_mask = vceqzq_f32(len_sq);
+                let sel_x = vbslq_f32(zero_mask, x, nx);
+                let sel_y = vbslq_f32(zero_mask, y, ny);
+                let sel_z = vbslq_f32(zero_mask, z, nz);
+
+                let scale = vdupq_n_f32(100.0);
+                let bias = vdupq_n_f32(100.0);
+                let ox = vaddq_f32(vmulq_f32(sel_x, scale), bias);
+                let oy = vaddq_f32(vmulq_f32(sel_y, scale), bias);
+                let oz = vaddq_f32(vmulq_f32(sel_z, scale), bias);
+
+                let mut out_x = [0f32; 4];
+                let mut out_y = [0f32; 4];
+                let mut out_z = [0f32; 4];
+                vst1q_f32(out_x.as_mut_ptr(), ox);
+                vst1q_f32(out_y.as_mut_ptr(), oy);
+                vst1q_f32(out_z.as_mut_ptr(), oz);
+
+                for lane in 0..4 {
+                    let off = (base + lane) * 3;
+                    outc[off] = out_x[lane].clamp(0.0, 255.0) as u8;
+                    outc[off + 1] = out_y[lane].clamp(0.0, 255.0) as u8;
+                    outc[off + 2] = out_z[lane].clamp(0.0, 255.0) as u8;
+                }
+            }
+        }
+
+        let remainder_in = chunks_in.remainder();
+        let remainder_out = chunks_out.into_remainder();
+
+        let mut sub_chunks_in = remainder_in.chunks_exact(12);
+        let mut sub_chunks_out = remainder_out.chunks_exact_mut(12);
+        for (inc, outc) in sub_chunks_in.by_ref().zip(sub_chunks_out.by_ref()) {
+            for i in 0..4 {
+                let off = i * 3;
+                let (nx, ny, nz) = Self::normalize_lane_scalar(inc[off] as f32, inc[off + 1] as f32, inc[off + 2] as f32);
+                outc[off] = (nx * 100.0 + 100.0) as u8;
+                outc[off + 1] = (ny * 100.0 + 100.0) as u8;
+                outc[off + 2] = (nz * 100.0 + 100.0) as u8;
+            }
+        }
+    }
+
+    /// Dispatches to the SIMD backend picked at construction time
+    /// (`self.simd_level`), falling back to the scalar path when the host
+    /// doesn't support the relevant CPU feature.
+    #[inline(always)]
+    fn process_contiguous_chunk(level: KernelSimdLevel, in_slice: &[u8], out_slice: &mut [u8]) {
+        match level {
+            #[cfg(target_arch = "x86_64")]
+            KernelSimdLevel::Avx2 => unsafe { Self::process_contiguous_chunk_avx2(in_slice, out_slice) },
+            #[cfg(target_arch = "aarch64")]
+            KernelSimdLevel::Neon => unsafe { Self::process_contiguous_chunk_neon(in_slice, out_slice) },
+            KernelSimdLevel::Scalar => Self::process_contiguous_chunk_scalar(in_slice, out_slice),
+        }
+    }
+
     pub fn process_tensor_stream(&mut self) -> i32 {
+        self.process_tensor_stream_inner(None)
+    }
+
+    /// Bounded variant of `process_tensor_stream`: stops once `max_bytes`
+    /// have been processed (or the stream runs dry, whichever comes
+    /// first) instead of always draining everything available, so a
+    /// caller can drive processing in cooperative sub-steps.
+    pub fn process_tensor_stream_bounded(&mut self, max_bytes: usize) -> i32 {
+        self.process_tensor_stream_inner(Some(max_bytes))
+    }
+
+    fn process_tensor_stream_inner(&mut self, byte_cap: Option<usize>) -> i32 {
         let mut processed = 0;
 
         if self.canary != 0xAA || self.tail_canary != 0x55 {
@@ -156,7 +411,10 @@ impl NativeKernel {
         let available = self.diff();
         if available < 3 { return 0; }
 
-        let mut remaining = available;
+        let mut remaining = match byte_cap {
+            Some(cap) => available.min(cap),
+            None => available,
+        };
 
         // 1. Process Contiguous Blocks (SIMD-Friendly)
         while remaining >= 48 {
@@ -170,7 +428,7 @@ impl NativeKernel {
                     let in_slice = &self.buffer[self.read_head .. self.read_head + chunk_len];
                     let out_slice = &mut self.output_buffer[self.read_head .. self.read_head + chunk_len];
 
-                    Self::process_contiguous_chunk_simd(in_slice, out_slice);
+                    Self::process_contiguous_chunk(self.simd_level, in_slice, out_slice);
 
                     self.read_head = (self.read_head + chunk_len) & MASK;
                     processed += chunk_len;
@@ -218,7 +476,105 @@ impl NativeKernel {
         processed as i32
     }
 
+    /// Parallel entry point for `process_tensor_stream`. Each 48-byte block
+    /// in `read_head..read_head+available` normalizes independently of the
+    /// others, so the contiguous region can be tree-split into fixed-size
+    /// subranges and processed with `par_chunks_mut`, then joined back into
+    /// a single processed-byte count — the same independent-subtree-then-join
+    /// shape BLAKE3 uses for its input tree.
+    ///
+    /// Falls back to the serial path below `min_len_for_threads` to avoid
+    /// thread-spawn overhead on small batches. Feature-gated behind
+    /// `parallel` so the no_std/WASM build is unaffected.
+    #[cfg(feature = "parallel")]
+    pub fn process_tensor_stream_parallel(&mut self, min_len_for_threads: usize) -> i32 {
+        use rayon::prelude::*;
+
+        if self.canary != 0xAA || self.tail_canary != 0x55 {
+            panic!("KERNEL PANIC: Canary corrupted! Memory violation detected.");
+        }
+
+        let available = self.diff();
+        if available < min_len_for_threads {
+            return self.process_tensor_stream();
+        }
+
+        // Split at the wrap boundary so every task sees a contiguous,
+        // non-wrapping slice; the wrapped remainder (if any) is handed to
+        // the serial path, which already knows how to walk across the wrap.
+        let contiguous_len = std::cmp::min(available, BUFFER_SIZE - self.read_head);
+        let parallel_len = (contiguous_len / 48) * 48; // align to whole 48-byte blocks
+        if parallel_len == 0 {
+            return self.process_tensor_stream();
+        }
+
+        const SUBRANGE_BYTES: usize = 4096;
+        let subrange_len = (SUBRANGE_BYTES / 48) * 48;
+
+        let read_head = self.read_head;
+        let simd_level = self.simd_level;
+        let in_region = &self.buffer[read_head..read_head + parallel_len];
+        let out_region = &mut self.output_buffer[read_head..read_head + parallel_len];
+
+        in_region
+            .par_chunks(subrange_len)
+            .zip(out_region.par_chunks_mut(subrange_len))
+            .for_each(|(in_chunk, out_chunk)| {
+                Self::process_contiguous_chunk(simd_level, in_chunk, out_chunk);
+            });
+
+        self.read_head = (self.read_head + parallel_len) & MASK;
+        let mut processed = parallel_len as i32;
+
+        // Whatever's left (remainder below 48 bytes, or data past the wrap)
+        // runs on the serial path on this thread. When `read_head` starts
+        // near the end of the buffer, that can be most of the batch.
+        processed += self.process_tensor_stream();
+        processed
+    }
+
     pub fn vector_add_batch(&mut self, count: i32) -> i32 {
+        // The add is a flat, position-independent byte op (out[i] += in[i]),
+        // so a batch that doesn't wrap can be SIMD'd as a plain byte array
+        // starting at read_head; a batch that wraps past the end of the
+        // buffer takes the scalar, index-stepping path as a whole.
+        let n = count as usize;
+        let fits_contiguous = self.read_head + n * 3 <= BUFFER_SIZE;
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            if self.simd_level == KernelSimdLevel::Avx2 && fits_contiguous && n > 0 {
+                let start = self.read_head;
+                let len = n * 3;
+                unsafe {
+                    Self::add_bytes_avx2(&self.buffer[start..start + len], &mut self.output_buffer[start..start + len]);
+                }
+                return n as i32;
+            }
+        }
+
+        self.vector_add_batch_scalar(count)
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx2")]
+    unsafe fn add_bytes_avx2(in_slice: &[u8], out_slice: &mut [u8]) {
+        use std::arch::x86_64::*;
+
+        let mut chunks_in = in_slice.chunks_exact(32);
+        let mut chunks_out = out_slice.chunks_exact_mut(32);
+        for (inc, outc) in chunks_in.by_ref().zip(chunks_out.by_ref()) {
+            let a = _mm256_loadu_si256(inc.as_ptr() as *const __m256i);
+            let b = _mm256_loadu_si256(outc.as_ptr() as *const __m256i);
+            let sum = _mm256_adds_epu8(a, b);
+            _mm256_storeu_si256(outc.as_mut_ptr() as *mut __m256i, sum);
+        }
+        for (i, o) in chunks_in.remainder().iter().zip(chunks_out.into_remainder().iter_mut()) {
+            *o = o.saturating_add(*i);
+        }
+    }
+
+    fn vector_add_batch_scalar(&mut self, count: i32) -> i32 {
         let n = count as usize;
         let mut processed = 0;
         let mut idx = self.read_head;
@@ -273,6 +629,60 @@ impl NativeKernel {
     }
 
     pub fn vector_dot_batch(&self, count: i32) -> i32 {
+        // Same flat-byte-op reasoning as vector_add_batch: only attempt the
+        // SIMD path over a non-wrapping run, otherwise defer to the scalar
+        // index-stepping implementation.
+        let n = count as usize;
+        let fits_contiguous = self.read_head + n * 3 <= BUFFER_SIZE;
+
+        #[cfg(target_arch = "x86_64")]
+        {
+            if self.simd_level == KernelSimdLevel::Avx2 && fits_contiguous && n > 0 {
+                let start = self.read_head;
+                let len = n * 3;
+                return unsafe { Self::dot_bytes_avx2(&self.buffer[start..start + len], &self.output_buffer[start..start + len]) };
+            }
+        }
+
+        self.vector_dot_batch_scalar(count)
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx2")]
+    unsafe fn dot_bytes_avx2(a_slice: &[u8], b_slice: &[u8]) -> i32 {
+        use std::arch::x86_64::*;
+
+        let mut acc = _mm256_setzero_si256();
+        let mut chunks_a = a_slice.chunks_exact(32);
+        let mut chunks_b = b_slice.chunks_exact(32);
+        for (ac, bc) in chunks_a.by_ref().zip(chunks_b.by_ref()) {
+            let a = _mm256_loadu_si256(ac.as_ptr() as *const __m256i);
+            let b = _mm256_loadu_si256(bc.as_ptr() as *const __m256i);
+            // Both operands are unsigned bytes in 0..=255, whose product
+            // (up to 255*255) doesn't fit the signed 8-bit lanes vpmaddubsw
+            // expects. Zero-extend each byte to a 16-bit lane first, then
+            // use the signed-16-bit madd, which the zero-extended values
+            // never saturate.
+            let a_lo = _mm256_unpacklo_epi8(a, _mm256_setzero_si256());
+            let a_hi = _mm256_unpackhi_epi8(a, _mm256_setzero_si256());
+            let b_lo = _mm256_unpacklo_epi8(b, _mm256_setzero_si256());
+            let b_hi = _mm256_unpackhi_epi8(b, _mm256_setzero_si256());
+            let prod_lo = _mm256_madd_epi16(a_lo, b_lo);
+            let prod_hi = _mm256_madd_epi16(a_hi, b_hi);
+            acc = _mm256_add_epi32(acc, _mm256_add_epi32(prod_lo, prod_hi));
+        }
+
+        let mut lanes = [0i32; 8];
+        _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, acc);
+        let mut dot_sum: i32 = lanes.iter().fold(0i32, |acc, &v| acc.wrapping_add(v));
+
+        for (i, o) in chunks_a.remainder().iter().zip(chunks_b.remainder().iter()) {
+            dot_sum = dot_sum.wrapping_add((*i as i32) * (*o as i32));
+        }
+        dot_sum
+    }
+
+    fn vector_dot_batch_scalar(&self, count: i32) -> i32 {
         let n = count as usize;
         let mut dot_sum: i32 = 0;
         let mut idx = self.read_head;
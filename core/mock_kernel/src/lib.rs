@@ -69,6 +69,128 @@ pub extern "C" fn get_output_byte(index: i32) -> i32 {
     }
 }
 
+// --- Typed Accessors ---
+// Byte-by-byte float packing (set_input_byte x3, read back x3) is how every
+// caller currently has to get a tensor in or out. These typed reads/writes
+// are a thin codec on top of the same BUFFER/OUTPUT_BUFFER, mirroring a
+// binary-reader's `c_u16b`/`c_i32b`-style typed access: pick little- or
+// big-endian explicitly, mask each byte index the same way
+// `set_input_3_bytes` already does.
+
+#[inline(always)]
+unsafe fn write_bytes_masked(buf: &mut [u8; BUFFER_SIZE], index: i32, bytes: &[u8]) {
+    if index < 0 {
+        return;
+    }
+    let idx = (index as usize) & (BUFFER_SIZE - 1);
+    for (i, b) in bytes.iter().enumerate() {
+        buf[(idx + i) & (BUFFER_SIZE - 1)] = *b;
+    }
+}
+
+#[inline(always)]
+unsafe fn read_bytes_masked<const N: usize>(buf: &[u8; BUFFER_SIZE], index: i32) -> [u8; N] {
+    let mut out = [0u8; N];
+    if index < 0 {
+        return out;
+    }
+    let idx = (index as usize) & (BUFFER_SIZE - 1);
+    for i in 0..N {
+        out[i] = buf[(idx + i) & (BUFFER_SIZE - 1)];
+    }
+    out
+}
+
+#[inline(always)]
+fn to_bytes_u16(val: u16, big_endian: bool) -> [u8; 2] {
+    if big_endian { val.to_be_bytes() } else { val.to_le_bytes() }
+}
+
+#[inline(always)]
+fn to_bytes_i32(val: i32, big_endian: bool) -> [u8; 4] {
+    if big_endian { val.to_be_bytes() } else { val.to_le_bytes() }
+}
+
+#[inline(always)]
+fn to_bytes_f32(val: f32, big_endian: bool) -> [u8; 4] {
+    if big_endian { val.to_be_bytes() } else { val.to_le_bytes() }
+}
+
+#[no_mangle]
+pub extern "C" fn set_input_u16(index: i32, val: i32, big_endian: i32) {
+    unsafe { write_bytes_masked(&mut BUFFER, index, &to_bytes_u16(val as u16, big_endian != 0)) }
+}
+
+#[no_mangle]
+pub extern "C" fn get_output_u16(index: i32, big_endian: i32) -> i32 {
+    unsafe {
+        let bytes: [u8; 2] = read_bytes_masked(&OUTPUT_BUFFER, index);
+        let val = if big_endian != 0 { u16::from_be_bytes(bytes) } else { u16::from_le_bytes(bytes) };
+        val as i32
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn set_input_i32(index: i32, val: i32, big_endian: i32) {
+    unsafe { write_bytes_masked(&mut BUFFER, index, &to_bytes_i32(val, big_endian != 0)) }
+}
+
+#[no_mangle]
+pub extern "C" fn get_output_i32(index: i32, big_endian: i32) -> i32 {
+    unsafe {
+        let bytes: [u8; 4] = read_bytes_masked(&OUTPUT_BUFFER, index);
+        if big_endian != 0 { i32::from_be_bytes(bytes) } else { i32::from_le_bytes(bytes) }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn set_input_f32(index: i32, val: f32, big_endian: i32) {
+    unsafe { write_bytes_masked(&mut BUFFER, index, &to_bytes_f32(val, big_endian != 0)) }
+}
+
+#[no_mangle]
+pub extern "C" fn get_output_f32(index: i32, big_endian: i32) -> f32 {
+    unsafe {
+        let bytes: [u8; 4] = read_bytes_masked(&OUTPUT_BUFFER, index);
+        if big_endian != 0 { f32::from_be_bytes(bytes) } else { f32::from_le_bytes(bytes) }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn set_input_vec3_f32(index: i32, x: f32, y: f32, z: f32, big_endian: i32) {
+    unsafe {
+        let be = big_endian != 0;
+        write_bytes_masked(&mut BUFFER, index, &to_bytes_f32(x, be));
+        write_bytes_masked(&mut BUFFER, index.wrapping_add(4), &to_bytes_f32(y, be));
+        write_bytes_masked(&mut BUFFER, index.wrapping_add(8), &to_bytes_f32(z, be));
+    }
+}
+
+/// Vec3 reads can't return three values over the WASM `extern "C"` ABI, so
+/// the triple is written into linear memory at `out_ptr` (12 bytes) instead
+/// — callers on the host side can read it back with a typed view over the
+/// same memory they gave us the offset into.
+#[no_mangle]
+pub extern "C" fn get_output_vec3_f32(index: i32, out_ptr: i32, big_endian: i32) {
+    unsafe {
+        let be = big_endian != 0;
+        let x: [u8; 4] = read_bytes_masked(&OUTPUT_BUFFER, index);
+        let y: [u8; 4] = read_bytes_masked(&OUTPUT_BUFFER, index.wrapping_add(4));
+        let z: [u8; 4] = read_bytes_masked(&OUTPUT_BUFFER, index.wrapping_add(8));
+        let (x, y, z) = (
+            if be { f32::from_be_bytes(x) } else { f32::from_le_bytes(x) },
+            if be { f32::from_be_bytes(y) } else { f32::from_le_bytes(y) },
+            if be { f32::from_be_bytes(z) } else { f32::from_le_bytes(z) },
+        );
+        if out_ptr >= 0 {
+            let ptr = out_ptr as *mut f32;
+            ptr.write_unaligned(x);
+            ptr.add(1).write_unaligned(y);
+            ptr.add(2).write_unaligned(z);
+        }
+    }
+}
+
 // --- Zero-Copy Interface (Genesis V3) ---
 // Returns the offset of the input buffer in Wasm Linear Memory.
 #[no_mangle]
@@ -183,31 +305,158 @@ pub extern "C" fn vector_add_batch(count: i32) -> i32 {
     }
 }
 
-// Simulated Heavy Compute: Matrix Multiplication (4x4)
+// --- Generalized Matrix Multiply ---
+// matrix_multiply_4x4 only covered a single hardcoded 4x4 u8 case, too
+// narrow for the "larger tensor operations" the 64KB buffer was sized for.
+// `matrix_multiply` below generalizes to arbitrary MxK * KxN and both
+// element types; `matrix_multiply_4x4` becomes a thin wrapper over it.
+
+/// Element type for `matrix_multiply`. `i32` values other than these are
+/// rejected (status -1) rather than silently defaulting.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum MatDType {
+    U8 = 0,
+    F32 = 1,
+}
+
+impl MatDType {
+    fn from_i32(v: i32) -> Option<Self> {
+        match v {
+            0 => Some(MatDType::U8),
+            1 => Some(MatDType::F32),
+            _ => None,
+        }
+    }
+
+    fn elem_size(self) -> usize {
+        match self {
+            MatDType::U8 => 1,
+            MatDType::F32 => 4,
+        }
+    }
+}
+
+#[inline(always)]
+unsafe fn read_mat_elem(buf: &[u8; BUFFER_SIZE], byte_off: usize, dtype: MatDType) -> f32 {
+    match dtype {
+        MatDType::U8 => buf[byte_off & (BUFFER_SIZE - 1)] as f32,
+        MatDType::F32 => {
+            let mut b = [0u8; 4];
+            for i in 0..4 {
+                b[i] = buf[(byte_off + i) & (BUFFER_SIZE - 1)];
+            }
+            f32::from_le_bytes(b)
+        }
+    }
+}
+
+#[inline(always)]
+unsafe fn write_mat_elem(buf: &mut [u8; BUFFER_SIZE], byte_off: usize, val: f32, dtype: MatDType) {
+    match dtype {
+        MatDType::U8 => buf[byte_off & (BUFFER_SIZE - 1)] = val.clamp(0.0, 255.0) as u8,
+        MatDType::F32 => {
+            let b = val.to_le_bytes();
+            for i in 0..4 {
+                buf[(byte_off + i) & (BUFFER_SIZE - 1)] = b[i];
+            }
+        }
+    }
+}
+
+const MATMUL_TILE: usize = 8;
+
+/// General `(m x k) * (k x n) -> (m x n)` matrix multiply with f32
+/// accumulation, row-major layout, an i-k-j loop order (inner loop streams
+/// contiguously over B and the output), and 8x8 register/cache blocking.
+///
+/// Returns a status code: `0` on success, `-1` for an unknown dtype, `-2`
+/// for non-positive dimensions, `-3` if any matrix doesn't fit in the 64KB
+/// buffer at the given offset.
+///
+/// `U8` output is `sum.rem_euclid(255.0)` over the `f32` accumulator above,
+/// which only matches the true integer `sum % 255` while every partial sum
+/// stays within `f32`'s 24-bit exact-integer range (`k` up to 258 terms when
+/// every product is 255*255). `matrix_multiply_4x4`'s fixed
+/// `k == 4` is always exact; large-`k` `U8` callers can see a modulo that
+/// silently drifts from the true value.
 #[no_mangle]
-pub extern "C" fn matrix_multiply_4x4(a_offset: i32, b_offset: i32, out_offset: i32) {
+pub extern "C" fn matrix_multiply(a_off: i32, b_off: i32, out_off: i32, m: i32, k: i32, n: i32, dtype: i32) -> i32 {
+    let dtype = match MatDType::from_i32(dtype) {
+        Some(d) => d,
+        None => return -1,
+    };
+    if m <= 0 || k <= 0 || n <= 0 {
+        return -2;
+    }
+    let (m, k, n) = (m as usize, k as usize, n as usize);
+    let elem = dtype.elem_size();
+
+    let a_bytes = m.checked_mul(k).and_then(|v| v.checked_mul(elem));
+    let b_bytes = k.checked_mul(n).and_then(|v| v.checked_mul(elem));
+    let out_bytes = m.checked_mul(n).and_then(|v| v.checked_mul(elem));
+    let (a_bytes, b_bytes, out_bytes) = match (a_bytes, b_bytes, out_bytes) {
+        (Some(a), Some(b), Some(o)) => (a, b, o),
+        _ => return -3,
+    };
+
+    let a_idx = (a_off as usize) & (BUFFER_SIZE - 1);
+    let b_idx = (b_off as usize) & (BUFFER_SIZE - 1);
+    let out_idx = (out_off as usize) & (BUFFER_SIZE - 1);
+    if a_idx + a_bytes > BUFFER_SIZE || b_idx + b_bytes > BUFFER_SIZE || out_idx + out_bytes > BUFFER_SIZE {
+        return -3;
+    }
+
     unsafe {
-        // Simple O(N^3) implementation for 4x4 matrix
-        let a_idx = (a_offset as usize) & (BUFFER_SIZE - 1);
-        let b_idx = (b_offset as usize) & (BUFFER_SIZE - 1);
-        let out_idx = (out_offset as usize) & (BUFFER_SIZE - 1);
+        let mut acc = [[0f32; MATMUL_TILE]; MATMUL_TILE];
+
+        let mut rb = 0;
+        while rb < m {
+            let r_end = (rb + MATMUL_TILE).min(m);
+            let mut cb = 0;
+            while cb < n {
+                let c_end = (cb + MATMUL_TILE).min(n);
+                for row in acc.iter_mut() {
+                    row.iter_mut().for_each(|v| *v = 0.0);
+                }
 
-        if a_idx + 16 > BUFFER_SIZE || b_idx + 16 > BUFFER_SIZE || out_idx + 16 > BUFFER_SIZE {
-            return;
-        }
+                // i-k-j order: for each row in the tile, walk k and fan the
+                // contribution out across the tile's columns so B and the
+                // accumulator are both read/written contiguously.
+                for r in rb..r_end {
+                    for kk in 0..k {
+                        let a_val = read_mat_elem(&BUFFER, a_idx + (r * k + kk) * elem, dtype);
+                        for c in cb..c_end {
+                            let b_val = read_mat_elem(&BUFFER, b_idx + (kk * n + c) * elem, dtype);
+                            acc[r - rb][c - cb] += a_val * b_val;
+                        }
+                    }
+                }
 
-        for r in 0..4 {
-            for c in 0..4 {
-                let mut sum: u32 = 0;
-                for k in 0..4 {
-                    let a_val = BUFFER[a_idx + r * 4 + k] as u32;
-                    let b_val = BUFFER[b_idx + k * 4 + c] as u32;
-                    sum += a_val * b_val;
+                for r in rb..r_end {
+                    for c in cb..c_end {
+                        let sum = acc[r - rb][c - cb];
+                        let out_val = match dtype {
+                            MatDType::U8 => (sum.rem_euclid(255.0)),
+                            MatDType::F32 => sum,
+                        };
+                        write_mat_elem(&mut OUTPUT_BUFFER, out_idx + (r * n + c) * elem, out_val, dtype);
+                    }
                 }
-                OUTPUT_BUFFER[out_idx + r * 4 + c] = (sum % 255) as u8;
+                cb += MATMUL_TILE;
             }
+            rb += MATMUL_TILE;
         }
     }
+
+    0
+}
+
+// Simulated Heavy Compute: Matrix Multiplication (4x4)
+// Thin backward-compatible wrapper over the general `matrix_multiply`.
+#[no_mangle]
+pub extern "C" fn matrix_multiply_4x4(a_offset: i32, b_offset: i32, out_offset: i32) {
+    matrix_multiply(a_offset, b_offset, out_offset, 4, 4, 4, MatDType::U8 as i32);
 }
 
 // New Function: Vector Dot Product (Batch)
@@ -254,3 +503,292 @@ pub extern "C" fn check_integrity() -> i32 {
         }
     }
 }
+
+// --- Op-Program Dispatcher ---
+// Every op above is its own extern "C" export, so a caller chaining e.g.
+// normalize -> dot -> add pays three FFI/WASM boundary crossings. This is a
+// tiny register-less executor that steps over an opcode stream encoded into
+// BUFFER instead: one crossing runs the whole pipeline.
+
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Opcode {
+    Normalize = 0x00,
+    VectorAdd = 0x01,
+    VectorDot = 0x02,
+    MatMul = 0x03,
+    SetReadHead = 0x04,
+    SetWriteHead = 0x05,
+    CheckIntegrity = 0x06,
+}
+
+impl Opcode {
+    fn from_byte(b: u8) -> Option<Self> {
+        match b {
+            0x00 => Some(Opcode::Normalize),
+            0x01 => Some(Opcode::VectorAdd),
+            0x02 => Some(Opcode::VectorDot),
+            0x03 => Some(Opcode::MatMul),
+            0x04 => Some(Opcode::SetReadHead),
+            0x05 => Some(Opcode::SetWriteHead),
+            0x06 => Some(Opcode::CheckIntegrity),
+            _ => None,
+        }
+    }
+}
+
+// Last 4 bytes of OUTPUT_BUFFER hold the dispatcher's accumulated result
+// word (last dot product / processed count / integrity flag), so a caller
+// can read the whole pipeline's outcome with a single typed load.
+const PROGRAM_RESULT_SLOT: usize = BUFFER_SIZE - 4;
+
+#[inline(always)]
+fn take_i32(program: &[u8], pos: &mut usize) -> Option<i32> {
+    let bytes: [u8; 4] = program.get(*pos..*pos + 4)?.try_into().ok()?;
+    *pos += 4;
+    Some(i32::from_le_bytes(bytes))
+}
+
+#[inline(always)]
+unsafe fn write_program_result(val: i32) {
+    OUTPUT_BUFFER[PROGRAM_RESULT_SLOT..PROGRAM_RESULT_SLOT + 4].copy_from_slice(&val.to_le_bytes());
+}
+
+/// Decodes and executes an opcode stream of `program_len` bytes starting at
+/// `program_offset` in `BUFFER`. Each instruction is 1 opcode byte followed
+/// by fixed-width little-endian i32 args (`MatMul` takes 7: a, b, out, m, k,
+/// n, dtype). Stops and returns an error code on the first malformed or
+/// out-of-bounds instruction; the canary is re-checked at program start and
+/// end so a corrupting op is caught before its result is trusted.
+///
+/// Returns: `0` success, `-1` malformed/unknown opcode or truncated args,
+/// `-2` an op's offset/length argument is out of `BUFFER_SIZE` bounds (or
+/// `matrix_multiply` returned any nonzero status), `-4` canary corrupted
+/// before the program ran, `-5` canary corrupted after it ran.
+#[no_mangle]
+pub extern "C" fn execute_program(program_offset: i32, program_len: i32) -> i32 {
+    unsafe {
+        if CANARY != 0xAA {
+            return -4;
+        }
+        if program_offset < 0 || program_len < 0 {
+            return -1;
+        }
+        let start = (program_offset as usize) & (BUFFER_SIZE - 1);
+        let len = program_len as usize;
+        if start + len > BUFFER_SIZE {
+            return -2;
+        }
+
+        let program = BUFFER[start..start + len].to_vec();
+        let mut pos = 0usize;
+        let mut result: i32 = 0;
+
+        while pos < program.len() {
+            let opcode = match program.get(pos).copied().and_then(Opcode::from_byte) {
+                Some(op) => op,
+                None => return -1,
+            };
+            pos += 1;
+
+            match opcode {
+                Opcode::Normalize => {
+                    result = process_tensor_stream();
+                }
+                Opcode::VectorAdd => {
+                    let count = match take_i32(&program, &mut pos) {
+                        Some(v) => v,
+                        None => return -1,
+                    };
+                    if count < 0 || count as usize > BUFFER_SIZE / 3 {
+                        return -2;
+                    }
+                    result = vector_add_batch(count);
+                }
+                Opcode::VectorDot => {
+                    let count = match take_i32(&program, &mut pos) {
+                        Some(v) => v,
+                        None => return -1,
+                    };
+                    if count < 0 || count as usize > BUFFER_SIZE / 3 {
+                        return -2;
+                    }
+                    result = vector_dot_batch(count);
+                }
+                Opcode::MatMul => {
+                    let (a, b, out, m, k, n, dtype) = match (
+                        take_i32(&program, &mut pos),
+                        take_i32(&program, &mut pos),
+                        take_i32(&program, &mut pos),
+                        take_i32(&program, &mut pos),
+                        take_i32(&program, &mut pos),
+                        take_i32(&program, &mut pos),
+                        take_i32(&program, &mut pos),
+                    ) {
+                        (Some(a), Some(b), Some(out), Some(m), Some(k), Some(n), Some(dtype)) => {
+                            (a, b, out, m, k, n, dtype)
+                        }
+                        _ => return -1,
+                    };
+                    let status = matrix_multiply(a, b, out, m, k, n, dtype);
+                    if status != 0 {
+                        return -2;
+                    }
+                    result = status;
+                }
+                Opcode::SetReadHead => {
+                    let pos_arg = match take_i32(&program, &mut pos) {
+                        Some(v) => v,
+                        None => return -1,
+                    };
+                    if pos_arg < 0 || pos_arg as usize >= BUFFER_SIZE {
+                        return -2;
+                    }
+                    if pos_arg >= 0 {
+                        READ_HEAD = (pos_arg as usize) & (BUFFER_SIZE - 1);
+                    }
+                }
+                Opcode::SetWriteHead => {
+                    let pos_arg = match take_i32(&program, &mut pos) {
+                        Some(v) => v,
+                        None => return -1,
+                    };
+                    if pos_arg < 0 || pos_arg as usize >= BUFFER_SIZE {
+                        return -2;
+                    }
+                    set_write_head(pos_arg);
+                }
+                Opcode::CheckIntegrity => {
+                    result = check_integrity();
+                }
+            }
+        }
+
+        write_program_result(result);
+
+        if CANARY != 0xAA {
+            return -5;
+        }
+        0
+    }
+}
+
+// --- Chunked Content Hash (Integrity V2) ---
+// The canary is a single 0xAA byte, so a stray write anywhere else in the
+// 64KB region passes unnoticed. This hashes the buffer in fixed 1KB chunks
+// (independently, so the same parallel/SIMD fan-out used for processing
+// could hash them too) and combines the chunk digests pairwise up a binary
+// tree into one 32-byte root, the same chunked-tree shape BLAKE3 uses.
+// The canary stays as the cheap fast-path pre-check; this runs on demand.
+
+const HASH_CHUNK_BYTES: usize = 1024;
+const HASH_DIGEST_BYTES: usize = 32;
+
+// Reserved digest scratch slots at the tail of OUTPUT_BUFFER, kept clear of
+// execute_program's 4-byte PROGRAM_RESULT_SLOT.
+const INPUT_HASH_SLOT: usize = BUFFER_SIZE - 4 - HASH_DIGEST_BYTES;
+const OUTPUT_HASH_SLOT: usize = INPUT_HASH_SLOT - HASH_DIGEST_BYTES;
+
+/// Absorbs `data` (at most `HASH_CHUNK_BYTES`) into a 32-byte digest via a
+/// 4-lane splitmix64-style mix. Not cryptographically hardened, but catches
+/// chunk-granularity corruption a single canary byte never would.
+fn hash_chunk(data: &[u8]) -> [u8; HASH_DIGEST_BYTES] {
+    let mut lanes: [u64; 4] = [
+        0x9E3779B97F4A7C15,
+        0xBF58476D1CE4E5B9,
+        0x94D049BB133111EB,
+        0x2545F4914F6CDD1D,
+    ];
+    for (i, word) in data.chunks(8).enumerate() {
+        let mut buf = [0u8; 8];
+        buf[..word.len()].copy_from_slice(word);
+        let lane = &mut lanes[i % 4];
+        *lane ^= u64::from_le_bytes(buf);
+        *lane = lane.wrapping_mul(0x9E3779B97F4A7C15);
+        *lane ^= *lane >> 29;
+        *lane = lane.wrapping_mul(0xBF58476D1CE4E5B9);
+        *lane ^= *lane >> 32;
+    }
+    let mut out = [0u8; HASH_DIGEST_BYTES];
+    for (i, lane) in lanes.iter().enumerate() {
+        out[i * 8..i * 8 + 8].copy_from_slice(&lane.to_le_bytes());
+    }
+    out
+}
+
+/// Combines two child digests into their parent tree node.
+fn combine_digests(left: &[u8; HASH_DIGEST_BYTES], right: &[u8; HASH_DIGEST_BYTES]) -> [u8; HASH_DIGEST_BYTES] {
+    let mut combined = [0u8; HASH_DIGEST_BYTES * 2];
+    combined[..HASH_DIGEST_BYTES].copy_from_slice(left);
+    combined[HASH_DIGEST_BYTES..].copy_from_slice(right);
+    hash_chunk(&combined)
+}
+
+fn hash_region(buf: &[u8]) -> [u8; HASH_DIGEST_BYTES] {
+    let mut level: Vec<[u8; HASH_DIGEST_BYTES]> = buf.chunks(HASH_CHUNK_BYTES).map(hash_chunk).collect();
+    if level.is_empty() {
+        return [0u8; HASH_DIGEST_BYTES];
+    }
+    while level.len() > 1 {
+        let mut next = Vec::with_capacity(level.len().div_ceil(2));
+        for pair in level.chunks(2) {
+            if pair.len() == 2 {
+                next.push(combine_digests(&pair[0], &pair[1]));
+            } else {
+                next.push(pair[0]);
+            }
+        }
+        level = next;
+    }
+    level[0]
+}
+
+/// Root digest over the current input buffer.
+pub fn hash_input() -> [u8; HASH_DIGEST_BYTES] {
+    unsafe { hash_region(&BUFFER) }
+}
+
+/// Root digest over the current output buffer.
+pub fn hash_output() -> [u8; HASH_DIGEST_BYTES] {
+    unsafe { hash_region(&OUTPUT_BUFFER) }
+}
+
+/// True if the output buffer's current root digest matches `expected`,
+/// giving callers end-to-end verification that a compute pass produced the
+/// result they expect rather than silently-corrupted memory.
+pub fn verify_output(expected: &[u8; HASH_DIGEST_BYTES]) -> bool {
+    hash_output() == *expected
+}
+
+#[no_mangle]
+pub extern "C" fn hash_input_to_buffer() {
+    let digest = hash_input();
+    unsafe {
+        OUTPUT_BUFFER[INPUT_HASH_SLOT..INPUT_HASH_SLOT + HASH_DIGEST_BYTES].copy_from_slice(&digest);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn hash_output_to_buffer() {
+    let digest = hash_output();
+    unsafe {
+        OUTPUT_BUFFER[OUTPUT_HASH_SLOT..OUTPUT_HASH_SLOT + HASH_DIGEST_BYTES].copy_from_slice(&digest);
+    }
+}
+
+/// Reads a 32-byte expected digest from WASM linear memory at `expected_ptr`
+/// and compares it against the current output buffer's root digest.
+#[no_mangle]
+pub extern "C" fn verify_output_hash(expected_ptr: i32) -> i32 {
+    if expected_ptr < 0 {
+        return 0;
+    }
+    let mut expected = [0u8; HASH_DIGEST_BYTES];
+    unsafe {
+        let ptr = expected_ptr as *const u8;
+        for i in 0..HASH_DIGEST_BYTES {
+            expected[i] = ptr.add(i).read_unaligned();
+        }
+    }
+    verify_output(&expected) as i32
+}